@@ -1,18 +1,85 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, PoisonError};
 
 use fake::Fake;
-use rand::{distributions::Alphanumeric, seq::SliceRandom, thread_rng, Rng};
+use rand::{distributions::Alphanumeric, rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 
-use serde_json::{from_value, to_value};
+use serde_json::{from_value, to_value, Map};
 use tera::{Context, Function, Result, Tera, Value};
 
 const FORMAT_ISO: &str = "%FT%T%z";
 
+const PRODUCT_ADJECTIVES: &[&str] = &[
+    "Awesome",
+    "Ergonomic",
+    "Rustic",
+    "Intelligent",
+    "Gorgeous",
+    "Incredible",
+    "Fantastic",
+    "Practical",
+    "Sleek",
+    "Handcrafted",
+];
+const PRODUCT_MATERIALS: &[&str] = &[
+    "Steel", "Wooden", "Concrete", "Plastic", "Cotton", "Granite", "Rubber", "Metal", "Soft",
+    "Fresh",
+];
+const PRODUCT_NOUNS: &[&str] = &[
+    "Chair", "Car", "Computer", "Keyboard", "Mouse", "Bike", "Ball", "Gloves", "Pants", "Shirt",
+    "Table", "Shoes", "Hat", "Pizza", "Salad", "Cheese", "Bacon", "Fish", "Chicken",
+];
+const DEPARTMENTS: &[&str] = &[
+    "Books",
+    "Movies",
+    "Music",
+    "Games",
+    "Electronics",
+    "Computers",
+    "Home",
+    "Garden",
+    "Tools",
+    "Grocery",
+    "Health",
+    "Beauty",
+    "Toys",
+    "Kids",
+    "Baby",
+    "Clothing",
+    "Shoes",
+    "Jewelry",
+    "Sports",
+    "Outdoors",
+    "Automotive",
+    "Industrial",
+];
+const COLORS: &[&str] = &[
+    "red", "orange", "yellow", "green", "blue", "indigo", "violet", "black", "white", "gray",
+    "pink", "brown", "cyan", "magenta", "teal", "maroon", "navy", "olive", "silver", "gold",
+];
+
+/// Wraps an already-registered generator so it can be called again by name, e.g. from the
+/// `unique` wrapper.
+struct SharedGenerator(Arc<dyn Function>);
+
+impl Function for SharedGenerator {
+    fn call(&self, args: &HashMap<String, Value>) -> Result<Value> {
+        self.0.call(args)
+    }
+
+    fn is_safe(&self) -> bool {
+        self.0.is_safe()
+    }
+}
+
 pub struct DocumentRenderer {
     generators: HashMap<String, String>,
+    raw_generators: HashMap<String, Arc<dyn Function>>,
     tera: Tera,
+    rng: Arc<Mutex<StdRng>>,
+    uniques: Arc<Mutex<HashMap<String, HashSet<String>>>>,
 }
 
 impl DocumentRenderer {
@@ -29,33 +96,84 @@ impl DocumentRenderer {
         self.generators.clone()
     }
 
+    /// Generates a random JSON document conforming to a JSON Type Definition (RFC 8927)
+    /// schema, so users can point fakebeat at an existing JTD instead of hand-writing a
+    /// Tera template. Draws all randomness from the shared seeded RNG.
+    pub fn render_from_schema(&mut self, jtd: &Value) -> anyhow::Result<String> {
+        let definitions = jtd
+            .get("definitions")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut rng = self.rng.lock().unwrap_or_else(PoisonError::into_inner);
+        let document = generate_from_jtd(jtd, &definitions, &mut rng)?;
+
+        Ok(document.to_string())
+    }
+
     fn register_generator<F: Function + 'static>(&mut self, name: &str, desc: &str, function: F) {
-        self.tera.register_function(name, function);
+        let function: Arc<dyn Function> = Arc::new(function);
+        self.tera
+            .register_function(name, SharedGenerator(function.clone()));
+        self.raw_generators.insert(name.to_owned(), function);
         self.generators.insert(name.to_owned(), desc.to_owned());
     }
 
     fn register_generators(&mut self) {
+        let rng = self.rng.clone();
         self.register_generator(
             "date",
-            "Generates random date. Optional negative offset can be passed to specify the amount of days to be subtracted, via 'sub_rnd_days' param.",
+            "Generates a random date. Optional 'format' (a chrono strftime string, default ISO, or 'epoch_millis' for a millisecond timestamp number) controls the output layout; 'start'/'end' (RFC3339 strings or relative offsets like '-30d'/'+1h') pick a uniformly random timestamp inside that window; the legacy 'sub_rnd_days' subtracts a random number of days from now.",
             Box::new(move |args: &HashMap<String, Value>| -> Result<Value> {
-                match args.get("sub_rnd_days") {
-                    Some(sub_rnd_days) => match from_value::<i64>(sub_rnd_days.clone()) {
+                let format = args
+                    .get("format")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or(FORMAT_ISO);
+
+                if let Some(sub_rnd_days) = args.get("sub_rnd_days") {
+                    return match from_value::<i64>(sub_rnd_days.clone()) {
                         Ok(sub_rnd_days) => {
-                            let mut rng = rand::thread_rng();
+                            let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
 
                             let random_offset = rng.gen_range(0..sub_rnd_days);
                             let dt = Utc::now() - Duration::days(random_offset);
 
-                            Ok(to_value(dt.format(FORMAT_ISO).to_string()).unwrap())
+                            Ok(format_date(dt, format))
                         }
                         Err(_) => Err("".into()),
-                    },
-                    None => {
-                        let now = Utc::now().format(FORMAT_ISO);
-                        Ok(to_value(now.to_string()).unwrap())
-                    }
+                    };
                 }
+
+                if args.get("start").is_none() && args.get("end").is_none() {
+                    return Ok(format_date(Utc::now(), format));
+                }
+
+                let start = match args.get("start").and_then(|v| v.as_str()).map(parse_date_bound)
+                {
+                    Some(Ok(dt)) => dt,
+                    Some(Err(err)) => return Err(err.into()),
+                    None => Utc::now(),
+                };
+
+                let end = match args.get("end").and_then(|v| v.as_str()).map(parse_date_bound) {
+                    Some(Ok(dt)) => dt,
+                    Some(Err(err)) => return Err(err.into()),
+                    None => Utc::now(),
+                };
+
+                let (start, end) = if start <= end { (start, end) } else { (end, start) };
+
+                let dt = if start == end {
+                    start
+                } else {
+                    let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                    let random_ts = rng.gen_range(start.timestamp_millis()..=end.timestamp_millis());
+
+                    Utc.timestamp_millis_opt(random_ts).unwrap()
+                };
+
+                Ok(format_date(dt, format))
             }),
         );
 
@@ -68,11 +186,13 @@ impl DocumentRenderer {
             }),
         );
 
+        let rng = self.rng.clone();
         self.register_generator(
             "hash",
             "16-character long alpha-num hash",
             Box::new(move |_: &HashMap<String, Value>| -> Result<Value> {
-                let value = thread_rng()
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let value = (&mut *rng)
                     .sample_iter(&Alphanumeric)
                     .take(16)
                     .map(char::from)
@@ -84,17 +204,18 @@ impl DocumentRenderer {
             }),
         );
 
+        let rng = self.rng.clone();
         self.register_generator(
             "random_value",
             "Get random value from set configured with the 'options' parameter, eg. options='a|b|c'",
             move |args: &HashMap<String, Value>| -> Result<Value> {
-                let mut rng = thread_rng();
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
 
                 match args.get("options") {
                     Some(value) => {
                         if let Some(options) = value.as_str() {
                             let options: Vec<&str> = options.split("|").collect();
-                            let random_value = options.choose(&mut rng);
+                            let random_value = options.choose(&mut *rng);
 
                             if let Some(random_value) = random_value {
                                 Ok(random_value.to_owned().into())
@@ -110,11 +231,12 @@ impl DocumentRenderer {
             },
         );
 
+        let rng = self.rng.clone();
         self.register_generator(
             "chance",
             "Roll a dice within range, if 0 is rolled then return first option, else 2nd",
             move |args: &HashMap<String, Value>| -> Result<Value> {
-                let mut rng = thread_rng();
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
                 let range = args.get("range").unwrap().as_u64().unwrap();
                 let options = args.get("options").unwrap().as_str().unwrap();
                 let chance = rng.gen_range(0..range);
@@ -127,11 +249,12 @@ impl DocumentRenderer {
                 }
             },
         );
+        let rng = self.rng.clone();
         self.register_generator(
             "randomint",
             "Roll a dice within a range",
             move |args: &HashMap<String, Value>| -> Result<Value> {
-                let mut rng = thread_rng();
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
                 let range = args
                     .get("range")
                     .unwrap_or(&Value::String("0".to_owned()))
@@ -143,11 +266,171 @@ impl DocumentRenderer {
             },
         );
 
+        let rng = self.rng.clone();
+        self.register_generator(
+            "price",
+            "Generates a random price with two decimal places between 'min' and 'max' (defaults 1.0 and 100.0)",
+            move |args: &HashMap<String, Value>| -> Result<Value> {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let min = args.get("min").and_then(|v| v.as_f64()).unwrap_or(1.0);
+                let max = args.get("max").and_then(|v| v.as_f64()).unwrap_or(100.0);
+                let (min, max) = if min <= max { (min, max) } else { (max, min) };
+
+                let price = if min == max {
+                    min
+                } else {
+                    rng.gen_range(min..max)
+                };
+
+                Ok(to_value(format!("{:.2}", price)).unwrap_or_default())
+            },
+        );
+
+        let rng = self.rng.clone();
+        self.register_generator(
+            "product_name",
+            "Generates a random product name, eg. 'Rustic Soft Shoes'",
+            move |_: &HashMap<String, Value>| -> Result<Value> {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let name = format!(
+                    "{} {} {}",
+                    PRODUCT_ADJECTIVES.choose(&mut *rng).unwrap(),
+                    PRODUCT_MATERIALS.choose(&mut *rng).unwrap(),
+                    PRODUCT_NOUNS.choose(&mut *rng).unwrap(),
+                );
+
+                Ok(to_value(name).unwrap_or_default())
+            },
+        );
+
+        let rng = self.rng.clone();
+        self.register_generator(
+            "department",
+            "Generates a random store department name",
+            move |_: &HashMap<String, Value>| -> Result<Value> {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let value = DEPARTMENTS.choose(&mut *rng).unwrap();
+
+                Ok(to_value(value).unwrap_or_default())
+            },
+        );
+
+        let rng = self.rng.clone();
+        self.register_generator(
+            "color",
+            "Generates a random color name",
+            move |_: &HashMap<String, Value>| -> Result<Value> {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let value = COLORS.choose(&mut *rng).unwrap();
+
+                Ok(to_value(value).unwrap_or_default())
+            },
+        );
+
+        let rng = self.rng.clone();
+        self.register_generator(
+            "creditcard",
+            "Generates a Luhn-valid credit card number. Optional 'type' parameter selects the issuer: visa, mastercard or amex (default visa).",
+            move |args: &HashMap<String, Value>| -> Result<Value> {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let card_type = args.get("type").and_then(|v| v.as_str()).unwrap_or("visa");
+
+                let (prefixes, length): (&[&str], usize) = match card_type {
+                    "mastercard" => (&["51", "52", "53", "54", "55"], 16),
+                    "amex" => (&["34", "37"], 15),
+                    _ => (&["4"], 16),
+                };
+
+                let prefix = prefixes.choose(&mut *rng).unwrap();
+                let mut digits: Vec<u32> = prefix.chars().map(|c| c.to_digit(10).unwrap()).collect();
+
+                while digits.len() < length - 1 {
+                    digits.push(rng.gen_range(0..10));
+                }
+
+                let checksum: u32 = digits
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .map(|(i, d)| {
+                        if i % 2 == 0 {
+                            let doubled = d * 2;
+                            if doubled > 9 {
+                                doubled - 9
+                            } else {
+                                doubled
+                            }
+                        } else {
+                            *d
+                        }
+                    })
+                    .sum();
+
+                digits.push((10 - (checksum % 10)) % 10);
+
+                let number: String = digits
+                    .iter()
+                    .map(|d| std::char::from_digit(*d, 10).unwrap())
+                    .collect();
+
+                Ok(to_value(number).unwrap_or_default())
+            },
+        );
+
+        let rng = self.rng.clone();
+        self.register_generator(
+            "geopoint",
+            "Generates a random { lat, lon } object for Elasticsearch geo_point fields. Pass format='string' for a 'lat,lon' string instead.",
+            move |args: &HashMap<String, Value>| -> Result<Value> {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let lat: f64 = rng.gen_range(-90.0..=90.0);
+                let lon: f64 = rng.gen_range(-180.0..=180.0);
+
+                if args.get("format").and_then(|v| v.as_str()) == Some("string") {
+                    return Ok(to_value(format!("{},{}", lat, lon)).unwrap_or_default());
+                }
+
+                let mut point = Map::new();
+                point.insert("lat".to_owned(), to_value(lat).unwrap_or_default());
+                point.insert("lon".to_owned(), to_value(lon).unwrap_or_default());
+
+                Ok(Value::Object(point))
+            },
+        );
+
+        let rng = self.rng.clone();
+        self.register_generator(
+            "uuid",
+            "Generates a random UUID v4",
+            move |_: &HashMap<String, Value>| -> Result<Value> {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let value: String = fake::uuid::UUIDv4.fake_with_rng(&mut *rng);
+
+                Ok(to_value(value).unwrap_or_default())
+            },
+        );
+
+        let rng = self.rng.clone();
+        self.register_generator(
+            "ulid",
+            "Generates a random ULID",
+            move |_: &HashMap<String, Value>| -> Result<Value> {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                let value = fake::Faker
+                    .fake_with_rng::<ulid::Ulid, _>(&mut *rng)
+                    .to_string();
+
+                Ok(to_value(value).unwrap_or_default())
+            },
+        );
+
         macro_rules! register_faker_generators {
             (    $($i:ident: $p:path), *) => {
                     $(
+                        let rng = self.rng.clone();
                         self.register_generator(stringify!($i), stringify!($p), Box::new(move |_: &HashMap<String, Value>| -> Result<Value> {
-                            let value = to_value($p().fake::<String>()).unwrap_or_default();
+                            let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                            let value = to_value($p().fake_with_rng::<String, _>(&mut *rng)).unwrap_or_default();
                             Ok(value)
                         }));
                     )*
@@ -213,22 +496,266 @@ impl DocumentRenderer {
             latitude: fake::faker::address::en::Latitude,
             longitude: fake::faker::address::en::Longitude
         );
+
+        let raw_generators = self.raw_generators.clone();
+        let uniques = self.uniques.clone();
+        self.register_generator(
+            "unique",
+            "Wraps another generator named via the 'of' parameter so it never emits the same value twice in a single run, eg. unique(of='username')",
+            move |args: &HashMap<String, Value>| -> Result<Value> {
+                let of = match args.get("of").and_then(|v| v.as_str()) {
+                    Some(of) => of,
+                    None => return Err("unique() requires an 'of' parameter naming the wrapped generator".into()),
+                };
+
+                let generator = match raw_generators.get(of) {
+                    Some(generator) => generator,
+                    None => return Err(format!("unique(): unknown generator '{}'", of).into()),
+                };
+
+                let forwarded_args: HashMap<String, Value> = args
+                    .iter()
+                    .filter(|(key, _)| key.as_str() != "of")
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+
+                let mut uniques = uniques.lock().unwrap_or_else(PoisonError::into_inner);
+                let seen = uniques.entry(of.to_owned()).or_default();
+
+                for _ in 0..1000 {
+                    let candidate = generator.call(&forwarded_args)?;
+                    let key = candidate
+                        .as_str()
+                        .map(|s| s.to_owned())
+                        .unwrap_or_else(|| candidate.to_string());
+
+                    if seen.insert(key) {
+                        return Ok(candidate);
+                    }
+                }
+
+                Err(format!("unique pool exhausted for generator '{}'", of).into())
+            },
+        );
     }
 
-    fn new() -> Self {
+    fn new(rng: StdRng) -> Self {
         let tera = Tera::default();
 
         let generators = HashMap::<String, String>::new();
+        let raw_generators = HashMap::<String, Arc<dyn Function>>::new();
+
+        return Self {
+            tera,
+            generators,
+            raw_generators,
+            rng: Arc::new(Mutex::new(rng)),
+            uniques: Arc::new(Mutex::new(HashMap::new())),
+        };
+    }
+}
+
+/// Renders a date per the `date` generator's `format` argument: `epoch_millis` yields a JSON
+/// number of milliseconds since the Unix epoch, anything else is a chrono strftime string.
+fn format_date(dt: DateTime<Utc>, format: &str) -> Value {
+    if format == "epoch_millis" {
+        to_value(dt.timestamp_millis()).unwrap_or_default()
+    } else {
+        to_value(dt.format(format).to_string()).unwrap_or_default()
+    }
+}
+
+/// Parses a relative date offset such as `-30d`, `+1h`, `45m` or `10s`, returning the
+/// corresponding `Duration`, or `None` if `value` isn't in that shape.
+fn parse_relative_offset(value: &str) -> Option<Duration> {
+    let (sign, rest) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+
+    let split_at = rest.char_indices().last()?.0;
+    let (amount, unit) = rest.split_at(split_at);
+    let amount: i64 = amount.parse().ok()?;
+
+    let duration = match unit {
+        "d" => Duration::days(amount),
+        "h" => Duration::hours(amount),
+        "m" => Duration::minutes(amount),
+        "s" => Duration::seconds(amount),
+        _ => return None,
+    };
+
+    Some(duration * sign)
+}
+
+/// Resolves a `date` generator's `start`/`end` bound, which is either an RFC3339 timestamp or
+/// a relative offset (see `parse_relative_offset`) applied to now.
+fn parse_date_bound(value: &str) -> std::result::Result<DateTime<Utc>, String> {
+    if let Some(offset) = parse_relative_offset(value) {
+        return Ok(Utc::now() + offset);
+    }
+
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|err| format!("invalid date bound '{}': {}", value, err))
+}
 
-        return Self { tera, generators };
+/// Returns the inclusive `(min, max)` range for a JTD integer type, or `None` if `type_name`
+/// does not name an integer type.
+fn int_bounds(type_name: &str) -> Option<(i64, i64)> {
+    match type_name {
+        "int8" => Some((i8::MIN as i64, i8::MAX as i64)),
+        "uint8" => Some((u8::MIN as i64, u8::MAX as i64)),
+        "int16" => Some((i16::MIN as i64, i16::MAX as i64)),
+        "uint16" => Some((u16::MIN as i64, u16::MAX as i64)),
+        "int32" => Some((i32::MIN as i64, i32::MAX as i64)),
+        "uint32" => Some((u32::MIN as i64, u32::MAX as i64)),
+        _ => None,
     }
 }
 
+/// Recursively walks a JSON Type Definition form and produces a random `serde_json::Value`
+/// that conforms to it, drawing randomness from `rng`. `definitions` is the root schema's
+/// `definitions` map, used to resolve `ref` forms.
+fn generate_from_jtd(
+    schema: &Value,
+    definitions: &Map<String, Value>,
+    rng: &mut StdRng,
+) -> anyhow::Result<Value> {
+    if let Some(name) = schema.get("ref").and_then(Value::as_str) {
+        let referenced = definitions
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("unresolved JTD ref '{}'", name))?;
+
+        return generate_from_jtd(referenced, definitions, rng);
+    }
+
+    if let Some(type_name) = schema.get("type").and_then(Value::as_str) {
+        return Ok(match type_name {
+            "boolean" => Value::Bool(rng.gen_bool(0.5)),
+            "string" => Value::String(fake::faker::lorem::en::Word().fake_with_rng(rng)),
+            "timestamp" => {
+                let random_offset = rng.gen_range(0..365);
+                let dt = Utc::now() - Duration::days(random_offset);
+
+                Value::String(dt.to_rfc3339())
+            }
+            "float32" => Value::from(rng.gen::<f32>() * 1000.0),
+            "float64" => Value::from(rng.gen::<f64>() * 1000.0),
+            _ => match int_bounds(type_name) {
+                Some((min, max)) => Value::from(rng.gen_range(min..=max)),
+                None => {
+                    return Err(anyhow::anyhow!("unsupported JTD type '{}'", type_name));
+                }
+            },
+        });
+    }
+
+    if let Some(members) = schema.get("enum").and_then(Value::as_array) {
+        let member = members
+            .choose(rng)
+            .ok_or_else(|| anyhow::anyhow!("JTD enum form has no members"))?;
+
+        return Ok(member.clone());
+    }
+
+    if let Some(sub_schema) = schema.get("elements") {
+        let len = rng.gen_range(0..=8);
+        let mut elements = Vec::with_capacity(len);
+
+        for _ in 0..len {
+            elements.push(generate_from_jtd(sub_schema, definitions, rng)?);
+        }
+
+        return Ok(Value::Array(elements));
+    }
+
+    if schema.get("properties").is_some() || schema.get("optionalProperties").is_some() {
+        let mut object = Map::new();
+
+        if let Some(props) = schema.get("properties").and_then(Value::as_object) {
+            for (key, sub_schema) in props {
+                object.insert(
+                    key.clone(),
+                    generate_from_jtd(sub_schema, definitions, rng)?,
+                );
+            }
+        }
+
+        if let Some(props) = schema.get("optionalProperties").and_then(Value::as_object) {
+            for (key, sub_schema) in props {
+                if rng.gen_bool(0.5) {
+                    object.insert(
+                        key.clone(),
+                        generate_from_jtd(sub_schema, definitions, rng)?,
+                    );
+                }
+            }
+        }
+
+        return Ok(Value::Object(object));
+    }
+
+    if let Some(sub_schema) = schema.get("values") {
+        let len = rng.gen_range(0..=8);
+        let mut object = Map::new();
+
+        for _ in 0..len {
+            let key = (&mut *rng)
+                .sample_iter(&Alphanumeric)
+                .take(8)
+                .map(char::from)
+                .collect::<String>();
+
+            object.insert(key, generate_from_jtd(sub_schema, definitions, rng)?);
+        }
+
+        return Ok(Value::Object(object));
+    }
+
+    if let Some(tag) = schema.get("discriminator").and_then(Value::as_str) {
+        let mapping = schema
+            .get("mapping")
+            .and_then(Value::as_object)
+            .ok_or_else(|| anyhow::anyhow!("JTD discriminator form is missing 'mapping'"))?;
+
+        let variant = mapping
+            .keys()
+            .collect::<Vec<_>>()
+            .choose(rng)
+            .map(|key| (*key).to_owned())
+            .ok_or_else(|| anyhow::anyhow!("JTD discriminator 'mapping' has no variants"))?;
+
+        let variant_schema = mapping.get(variant.as_str()).unwrap();
+        let mut object = generate_from_jtd(variant_schema, definitions, rng)?
+            .as_object()
+            .cloned()
+            .ok_or_else(|| {
+                anyhow::anyhow!("JTD discriminator mapping variants must use the properties form")
+            })?;
+
+        object.insert(tag.to_owned(), Value::String(variant));
+
+        return Ok(Value::Object(object));
+    }
+
+    // The empty form accepts any value; there is nothing to generate against.
+    Ok(Value::Null)
+}
+
 pub struct DocumentRendererFactory {}
 
 impl DocumentRendererFactory {
+    /// Creates a renderer whose generators draw from a fresh, non-reproducible RNG.
     pub fn create_renderer() -> DocumentRenderer {
-        let mut document_renderer = DocumentRenderer::new();
+        Self::create_renderer_with_seed(rand::random())
+    }
+
+    /// Creates a renderer whose generators all draw from a single `StdRng` seeded with
+    /// `seed`, so that two renderers created with the same seed make identical random draws.
+    /// Generators that read the current time (`date`, `now`) still depend on the wall clock.
+    pub fn create_renderer_with_seed(seed: u64) -> DocumentRenderer {
+        let mut document_renderer = DocumentRenderer::new(StdRng::seed_from_u64(seed));
 
         document_renderer.register_generators();
 
@@ -240,7 +767,8 @@ impl DocumentRendererFactory {
 mod tests {
     const FORMAT_ISO: &str = "%FT%T%z";
 
-    use chrono::Utc;
+    use chrono::{Duration, Utc};
+    use serde_json::json;
 
     use crate::document_renderer::DocumentRendererFactory;
 
@@ -275,4 +803,167 @@ mod tests {
 
         assert_eq!(result.contains(&dt.format(FORMAT_ISO).to_string()), true);
     }
+
+    #[test]
+    fn it_produces_identical_output_for_the_same_seed() {
+        let mut first = DocumentRendererFactory::create_renderer_with_seed(42);
+        let mut second = DocumentRendererFactory::create_renderer_with_seed(42);
+
+        let template = r#"{{hash()}}-{{username()}}-{{randomint(range=1000)}}"#;
+
+        let first_result = first.render(template).unwrap();
+        let second_result = second.render(template).unwrap();
+
+        assert_eq!(first_result, second_result);
+    }
+
+    #[test]
+    fn it_generates_a_document_matching_a_jtd_schema() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let schema = json!({
+            "properties": {
+                "id": { "type": "string" },
+                "active": { "type": "boolean" }
+            },
+            "optionalProperties": {
+                "nickname": { "type": "string" }
+            }
+        });
+
+        let result = renderer.render_from_schema(&schema).unwrap();
+        let document: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+        assert!(document.get("id").unwrap().is_string());
+        assert!(document.get("active").unwrap().is_boolean());
+    }
+
+    #[test]
+    fn it_rejects_unresolved_jtd_refs() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+        let schema = json!({ "ref": "missing" });
+
+        assert!(renderer.render_from_schema(&schema).is_err());
+    }
+
+    #[test]
+    fn it_never_repeats_a_unique_value_and_errors_once_the_pool_is_exhausted() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let template = r#"{{unique(of="random_value", options="a|b")}}"#;
+
+        let first = renderer.render(template).unwrap();
+        let second = renderer.render(template).unwrap();
+
+        assert_ne!(first, second);
+        assert!(renderer.render(template).is_err());
+    }
+
+    #[test]
+    fn it_generates_luhn_valid_credit_card_numbers() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let result = renderer.render(r#"{{creditcard(type="visa")}}"#).unwrap();
+
+        assert_eq!(result.len(), 16);
+        assert_eq!(luhn_checksum(&result) % 10, 0);
+    }
+
+    fn luhn_checksum(number: &str) -> u32 {
+        number
+            .chars()
+            .rev()
+            .enumerate()
+            .map(|(i, c)| {
+                let digit = c.to_digit(10).unwrap();
+
+                if i % 2 == 1 {
+                    let doubled = digit * 2;
+                    if doubled > 9 {
+                        doubled - 9
+                    } else {
+                        doubled
+                    }
+                } else {
+                    digit
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn it_generates_a_date_within_the_given_start_and_end_bounds() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let result = renderer
+            .render(r#"{{date(start="-1d", end="+1d", format="epoch_millis")}}"#)
+            .unwrap();
+
+        let millis: i64 = result.parse().unwrap();
+        let now = Utc::now().timestamp_millis();
+
+        assert!(millis >= now - Duration::days(2).num_milliseconds());
+        assert!(millis <= now + Duration::days(2).num_milliseconds());
+    }
+
+    #[test]
+    fn it_formats_a_date_as_epoch_millis() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let result = renderer
+            .render(r#"{{date(format="epoch_millis")}}"#)
+            .unwrap();
+
+        let millis: i64 = result.parse().unwrap();
+        let now = Utc::now().timestamp_millis();
+
+        assert!((now - millis).abs() < 60_000);
+    }
+
+    #[test]
+    fn it_generates_a_valid_ulid() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let result = renderer.render(r#"{{ulid()}}"#).unwrap();
+
+        assert_eq!(result.len(), 26);
+        assert!(result.chars().all(|c| c.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    fn it_generates_a_valid_uuid() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let result = renderer.render(r#"{{uuid()}}"#).unwrap();
+
+        assert_eq!(result.len(), 36);
+    }
+
+    #[test]
+    fn it_generates_a_geopoint_object_with_lat_and_lon_in_range() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let result = renderer
+            .render(r#"{"point": {{geopoint() | json_encode()}}}"#)
+            .unwrap();
+        let document: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+        let lat = document["point"]["lat"].as_f64().unwrap();
+        let lon = document["point"]["lon"].as_f64().unwrap();
+
+        assert!((-90.0..=90.0).contains(&lat));
+        assert!((-180.0..=180.0).contains(&lon));
+    }
+
+    #[test]
+    fn it_generates_a_geopoint_string_when_format_is_string() {
+        let mut renderer = DocumentRendererFactory::create_renderer();
+
+        let result = renderer.render(r#"{{geopoint(format="string")}}"#).unwrap();
+        let parts: Vec<&str> = result.split(',').collect();
+
+        assert_eq!(parts.len(), 2);
+        assert!(parts[0].parse::<f64>().is_ok());
+        assert!(parts[1].parse::<f64>().is_ok());
+    }
 }